@@ -1,16 +1,35 @@
+#[cfg(any(
+    feature = "sqlx",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-mysql",
+    feature = "sqlx-any"
+))]
+mod sqlx_impl;
+mod typed;
 mod ulid;
 
+#[cfg(any(
+    feature = "sqlx",
+    feature = "sqlx-sqlite",
+    feature = "sqlx-mysql",
+    feature = "sqlx-any"
+))]
+pub use sqlx_impl::BinaryResourceID;
+pub use typed::{ResourceId, ResourceKind};
+
 use std::fmt;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
-use schemars::schema::{InstanceType, Metadata, Schema, SchemaObject};
+use schemars::schema::{InstanceType, Metadata, Schema, SchemaObject, StringValidation};
 use schemars::JsonSchema;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "sqlx")]
 use sqlx::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueRef};
+#[cfg(feature = "sqlx")]
 use sqlx::{Decode, Encode, Postgres, Type};
 use thiserror::Error;
-use crate::ulid::Ulid;
+use crate::ulid::{Ulid, UlidGenerator, UlidGeneratorError};
 
 #[derive(Debug, PartialEq, Eq, Error)]
 pub enum ResourceIDError {
@@ -22,6 +41,18 @@ pub enum ResourceIDError {
 
     #[error("Invalid ID length: {0} (expected 30)")]
     InvalidLength(String),
+
+    #[error("Unable to generate monotonic Ulid: {0}")]
+    UlidGenerationFailed(UlidGeneratorError),
+
+    #[cfg(any(
+        feature = "sqlx",
+        feature = "sqlx-sqlite",
+        feature = "sqlx-mysql",
+        feature = "sqlx-any"
+    ))]
+    #[error("Invalid binary ResourceID length: {0} (expected 20)")]
+    InvalidBinaryLength(usize),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -39,11 +70,20 @@ impl JsonSchema for ResourceID {
         SchemaObject {
             instance_type: Some(InstanceType::String.into()),
             format: Some("ResourceID".to_string()),
+            string: Some(Box::new(StringValidation {
+                max_length: Some(30),
+                min_length: Some(30),
+                pattern: Some(format!("^[A-Z]{{4}}{}", &ulid::ULID_PATTERN[1..])),
+            })),
             metadata: Some(Box::new(Metadata {
                 title: Some(String::from("ResourceID")),
                 description: Some(String::from(
                     "A unique resource identifier",
                 )),
+                examples: vec![serde_json::Value::String(format!(
+                    "USER{}",
+                    ulid::ULID_EXAMPLE
+                ))],
                 ..Default::default()
             })),
             ..Default::default()
@@ -67,22 +107,41 @@ impl ResourceID {
     }
 
 
-    fn validate_resource<S: ToString>(resource: S) -> Result<(), ResourceIDError> {
+    /// Like [`ResourceID::new`], but draws the `Ulid` from `generator`
+    /// instead of generating a fresh random one, so IDs minted through a
+    /// shared generator sort in emission order even within the same
+    /// millisecond.
+    pub fn new_monotonic<S: ToString>(
+        resource: S,
+        generator: &UlidGenerator,
+    ) -> Result<Self, ResourceIDError> {
+        let resource = resource.to_string().to_uppercase();
+
+        Self::validate_resource(&resource)?;
+
+        let ulid = generator
+            .generate()
+            .map_err(ResourceIDError::UlidGenerationFailed)?;
+
+        Ok(Self { resource, ulid })
+    }
+
+    pub(crate) fn validate_resource<S: ToString>(resource: S) -> Result<(), ResourceIDError> {
         let value = resource.to_string();
-        if value.len() != 4 {
+        if value.len() == 4 && value.bytes().all(|b| b.is_ascii_uppercase()) {
+            Ok(())
+        }
+        else {
             Err(ResourceIDError::InvalidResourceType(
                 value,
             ))
         }
-        else {
-            Ok(())
-        }
     }
 }
 
 impl Display for ResourceID {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}{}", self.resource, self.ulid.to_string())
+        write!(f, "{}{}", self.resource, self.ulid)
     }
 }
 
@@ -100,10 +159,11 @@ impl FromStr for ResourceID {
         let ulid_str = &s[4..];
         let ulid = Ulid::from_str(ulid_str).map_err(ResourceIDError::UnableToDecodeUlid)?;
 
-        Ok(ResourceID { resource: String::from(resource_str.to_uppercase()), ulid })
+        Ok(ResourceID { resource: resource_str.to_uppercase(), ulid })
     }
 }
 
+#[cfg(feature = "sqlx")]
 impl Type<Postgres> for ResourceID {
     fn type_info() -> PgTypeInfo {
         PgTypeInfo::with_name("VARCHAR")
@@ -114,17 +174,16 @@ impl Type<Postgres> for ResourceID {
     }
 }
 
+#[cfg(feature = "sqlx")]
 impl Encode<'_, Postgres> for ResourceID {
-    fn encode_by_ref(
-        &self,
-        buf: &mut PgArgumentBuffer,
-    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> sqlx::encode::IsNull {
         let value = self.to_string();
         buf.extend_from_slice(value.as_bytes());
-        Ok(sqlx::encode::IsNull::No)
+        sqlx::encode::IsNull::No
     }
 }
 
+#[cfg(feature = "sqlx")]
 impl<'r> Decode<'r, Postgres> for ResourceID {
     fn decode(value: PgValueRef<'r>) -> Result<Self, sqlx::error::BoxDynError> {
         let str_value = value.as_str()?;
@@ -132,6 +191,38 @@ impl<'r> Decode<'r, Postgres> for ResourceID {
     }
 }
 
+#[cfg(feature = "postgres")]
+impl postgres_types::ToSql for ResourceID {
+    fn to_sql(
+        &self,
+        ty: &postgres_types::Type,
+        out: &mut bytes::BytesMut,
+    ) -> Result<postgres_types::IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        <String as postgres_types::ToSql>::to_sql(&self.to_string(), ty, out)
+    }
+
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        matches!(*ty, postgres_types::Type::VARCHAR | postgres_types::Type::TEXT)
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+#[cfg(feature = "postgres")]
+impl<'a> postgres_types::FromSql<'a> for ResourceID {
+    fn from_sql(
+        ty: &postgres_types::Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let value = <&str as postgres_types::FromSql>::from_sql(ty, raw)?;
+        Ok(ResourceID::from_str(value)?)
+    }
+
+    fn accepts(ty: &postgres_types::Type) -> bool {
+        matches!(*ty, postgres_types::Type::VARCHAR | postgres_types::Type::TEXT)
+    }
+}
+
 impl Serialize for ResourceID {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -178,6 +269,17 @@ mod tests {
         assert_eq!(id.err(), Some(ResourceIDError::InvalidResourceType(invalid_id.to_string())));
     }
 
+    #[test]
+    fn resource_id_rejects_non_alphabetic_tag() {
+        let invalid_id = "US3R";
+
+        let id = ResourceID::new(invalid_id);
+
+        assert!(id.is_err());
+
+        assert_eq!(id.err(), Some(ResourceIDError::InvalidResourceType(invalid_id.to_string())));
+    }
+
     #[test]
     fn invalid_ulid_error() {
         let invalid_ulid = format!("USER{}", 1234);
@@ -203,7 +305,7 @@ mod tests {
         let valid_resource = "USER";
         let valid_ulid = Ulid::new();
 
-        let value = format!("{}{}", valid_resource, valid_ulid.to_string());
+        let value = format!("{}{}", valid_resource, valid_ulid);
 
         let id = value.parse::<ResourceID>();
 
@@ -215,6 +317,20 @@ mod tests {
         assert_eq!(id.ulid, valid_ulid);
     }
 
+    #[test]
+    fn new_monotonic_sorts_in_emission_order() {
+        let generator = UlidGenerator::new();
+
+        let ids: Vec<ResourceID> = (0..50)
+            .map(|_| ResourceID::new_monotonic("user", &generator).unwrap())
+            .collect();
+
+        let mut sorted = ids.clone();
+        sorted.sort_by_key(|id| id.to_string());
+
+        assert_eq!(ids, sorted);
+    }
+
     #[test]
     fn strum_resource_id() {
         #[derive(Debug, Clone, PartialEq, Eq, EnumString, Display, JsonSchema)]
@@ -233,4 +349,21 @@ mod tests {
 
         assert_eq!(account_resource.resource, ResourceIDResource::Account.to_string());
     }
+
+    #[test]
+    fn resource_id_schema_validates_examples() {
+        let schema = ResourceID::json_schema(&mut schemars::gen::SchemaGenerator::default());
+        let schema = schema.into_object();
+
+        let string = schema.string.expect("schema should have string validation");
+        assert_eq!(string.pattern.as_deref(), Some("^[A-Z]{4}[0-9A-HJKMNP-TV-Z]{26}$"));
+        assert_eq!(string.min_length, Some(30));
+        assert_eq!(string.max_length, Some(30));
+
+        let examples = &schema.metadata.expect("schema should have metadata").examples;
+        assert_eq!(examples.len(), 1);
+
+        let example = examples[0].as_str().unwrap();
+        assert!(example.parse::<ResourceID>().is_ok());
+    }
 }
@@ -0,0 +1,234 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use schemars::schema::{InstanceType, Metadata, Schema, SchemaObject, StringValidation};
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::ulid::{Ulid, UlidGenerator};
+use crate::{ResourceID, ResourceIDError};
+
+/// A 4-byte ASCII tag identifying a resource kind at the type level, so
+/// that e.g. `ResourceId<User>` and `ResourceId<Account>` can never be
+/// confused with each other.
+pub trait ResourceKind {
+    const TAG: &'static str;
+}
+
+/// A [`ResourceID`] whose resource tag is fixed at the type level by
+/// `K::TAG` instead of carried as a free-form `String`. `K::TAG` itself
+/// is validated at runtime, when an id is constructed.
+///
+/// `Debug`/`Clone`/`PartialEq`/`Eq`/`Hash` are implemented by hand rather
+/// than derived: `derive` would add a spurious `K: Trait` bound even
+/// though `K` only ever appears in a `PhantomData`.
+pub struct ResourceId<K: ResourceKind> {
+    ulid: Ulid,
+    _kind: PhantomData<K>,
+}
+
+impl<K: ResourceKind> fmt::Debug for ResourceId<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResourceId")
+            .field("resource", &K::TAG)
+            .field("ulid", &self.ulid)
+            .finish()
+    }
+}
+
+impl<K: ResourceKind> Clone for ResourceId<K> {
+    fn clone(&self) -> Self {
+        Self {
+            ulid: self.ulid.clone(),
+            _kind: PhantomData,
+        }
+    }
+}
+
+impl<K: ResourceKind> PartialEq for ResourceId<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ulid == other.ulid
+    }
+}
+
+impl<K: ResourceKind> Eq for ResourceId<K> {}
+
+impl<K: ResourceKind> std::hash::Hash for ResourceId<K> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.ulid.hash(state);
+    }
+}
+
+impl<K: ResourceKind> ResourceId<K> {
+    pub fn new() -> Result<Self, ResourceIDError> {
+        Self::validate_tag()?;
+
+        Ok(Self {
+            ulid: Ulid::new(),
+            _kind: PhantomData,
+        })
+    }
+
+    /// Like [`ResourceId::new`], but draws the `Ulid` from `generator`
+    /// instead of generating a fresh random one, so IDs minted through a
+    /// shared generator sort in emission order. See
+    /// [`ResourceID::new_monotonic`].
+    pub fn new_monotonic(generator: &UlidGenerator) -> Result<Self, ResourceIDError> {
+        Self::validate_tag()?;
+
+        let ulid = generator
+            .generate()
+            .map_err(ResourceIDError::UlidGenerationFailed)?;
+
+        Ok(Self {
+            ulid,
+            _kind: PhantomData,
+        })
+    }
+
+    fn validate_tag() -> Result<(), ResourceIDError> {
+        ResourceID::validate_resource(K::TAG)
+    }
+}
+
+impl<K: ResourceKind> From<ResourceId<K>> for ResourceID {
+    fn from(id: ResourceId<K>) -> Self {
+        ResourceID {
+            resource: K::TAG.to_string(),
+            ulid: id.ulid,
+        }
+    }
+}
+
+impl<K: ResourceKind> Display for ResourceId<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", K::TAG, self.ulid)
+    }
+}
+
+impl<K: ResourceKind> FromStr for ResourceId<K> {
+    type Err = ResourceIDError;
+
+    fn from_str(s: &str) -> Result<Self, ResourceIDError> {
+        let dynamic = ResourceID::from_str(s)?;
+
+        if dynamic.resource != K::TAG {
+            return Err(ResourceIDError::InvalidResourceType(dynamic.resource));
+        }
+
+        Ok(Self {
+            ulid: dynamic.ulid,
+            _kind: PhantomData,
+        })
+    }
+}
+
+impl<K: ResourceKind> Serialize for ResourceId<K> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de, K: ResourceKind> Deserialize<'de> for ResourceId<K> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl<K: ResourceKind> JsonSchema for ResourceId<K> {
+    fn schema_name() -> String {
+        format!("ResourceId_{}", K::TAG)
+    }
+
+    fn json_schema(_gen: &mut schemars::gen::SchemaGenerator) -> Schema {
+        SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            format: Some(format!("ResourceId<{}>", K::TAG)),
+            string: Some(Box::new(StringValidation {
+                max_length: Some(30),
+                min_length: Some(30),
+                pattern: Some(format!("^{}{}", K::TAG, &crate::ulid::ULID_PATTERN[1..])),
+            })),
+            metadata: Some(Box::new(Metadata {
+                title: Some(format!("{} ResourceId", K::TAG)),
+                description: Some(format!(
+                    "A unique identifier for the {} resource",
+                    K::TAG
+                )),
+                examples: vec![serde_json::Value::String(format!(
+                    "{}{}",
+                    K::TAG,
+                    crate::ulid::ULID_EXAMPLE
+                ))],
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct User;
+
+    impl ResourceKind for User {
+        const TAG: &'static str = "USER";
+    }
+
+    struct Account;
+
+    impl ResourceKind for Account {
+        const TAG: &'static str = "ACCT";
+    }
+
+    #[test]
+    fn typed_resource_id_round_trips() {
+        let id = ResourceId::<User>::new().unwrap();
+
+        let parsed = id.to_string().parse::<ResourceId<User>>().unwrap();
+
+        assert_eq!(id, parsed);
+    }
+
+    #[test]
+    fn typed_resource_id_rejects_mismatched_tag() {
+        let user_id = ResourceId::<User>::new().unwrap();
+
+        let result = user_id.to_string().parse::<ResourceId<Account>>();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn typed_resource_id_converts_to_dynamic() {
+        let id = ResourceId::<User>::new().unwrap();
+        let dynamic: ResourceID = id.into();
+
+        assert_eq!(dynamic.resource, User::TAG);
+    }
+
+    #[test]
+    fn typed_resource_id_schema_narrows_tag() {
+        let schema =
+            ResourceId::<User>::json_schema(&mut schemars::gen::SchemaGenerator::default());
+        let schema = schema.into_object();
+
+        let string = schema.string.expect("schema should have string validation");
+        assert_eq!(
+            string.pattern.as_deref(),
+            Some("^USER[0-9A-HJKMNP-TV-Z]{26}$")
+        );
+    }
+}
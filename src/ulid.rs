@@ -1,10 +1,25 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
 use std::ops::Deref;
 use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use schemars::schema::{InstanceType, Schema, SchemaObject};
+use schemars::schema::{InstanceType, Schema, SchemaObject, StringValidation};
 use schemars::JsonSchema;
+use thiserror::Error;
 pub use ulid::DecodeError;
 
+/// Number of bits in a ULID's random component.
+const RANDOM_BITS: u32 = 80;
+const RANDOM_MASK: u128 = (1u128 << RANDOM_BITS) - 1;
+
+/// Crockford base32 (excludes I/L/O/U), the alphabet ULIDs are encoded in.
+pub(crate) const ULID_PATTERN: &str = "^[0-9A-HJKMNP-TV-Z]{26}$";
+
+/// A valid ULID, used as the `examples` entry in the generated schema.
+pub(crate) const ULID_EXAMPLE: &str = "01ARZ3NDEKTSV4RRFFQ69G5FAV";
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Ulid(ulid::Ulid);
 
@@ -13,6 +28,93 @@ impl Ulid {
     pub fn new() -> Self {
         Ulid(ulid::Ulid::new())
     }
+
+    fn from_parts(timestamp_ms: u64, random: u128) -> Self {
+        Ulid(ulid::Ulid::from_parts(timestamp_ms, random))
+    }
+
+    #[cfg(any(
+        feature = "sqlx",
+        feature = "sqlx-sqlite",
+        feature = "sqlx-mysql",
+        feature = "sqlx-any"
+    ))]
+    pub(crate) fn as_u128(&self) -> u128 {
+        u128::from(self.0)
+    }
+
+    #[cfg(any(
+        feature = "sqlx",
+        feature = "sqlx-sqlite",
+        feature = "sqlx-mysql",
+        feature = "sqlx-any"
+    ))]
+    pub(crate) fn from_u128(value: u128) -> Self {
+        Ulid(ulid::Ulid::from(value))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum UlidGeneratorError {
+    #[error("random component overflowed within the same millisecond")]
+    RandomComponentOverflow,
+}
+
+/// Generates strictly increasing `Ulid`s, so that many minted within the
+/// same millisecond still sort in emission order.
+///
+/// Share a single `UlidGenerator` across an application (it is `Send` +
+/// `Sync`) to guarantee its output stays lexicographically ordered.
+pub struct UlidGenerator {
+    state: Mutex<(u64, u128)>,
+}
+
+impl UlidGenerator {
+    pub fn new() -> Self {
+        UlidGenerator {
+            state: Mutex::new((0, 0)),
+        }
+    }
+
+    pub fn generate(&self) -> Result<Ulid, UlidGeneratorError> {
+        let timestamp = Self::now_millis();
+        let mut state = self.state.lock().expect("UlidGenerator mutex poisoned");
+        let (last_timestamp, last_random) = *state;
+
+        let random = if timestamp > last_timestamp {
+            rand::random::<u128>() & RANDOM_MASK
+        } else {
+            let next = last_random + 1;
+            if next > RANDOM_MASK {
+                return Err(UlidGeneratorError::RandomComponentOverflow);
+            }
+            next
+        };
+
+        let effective_timestamp = timestamp.max(last_timestamp);
+        *state = (effective_timestamp, random);
+
+        Ok(Ulid::from_parts(effective_timestamp, random))
+    }
+
+    fn now_millis() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock is before the Unix epoch")
+            .as_millis() as u64
+    }
+}
+
+impl Default for UlidGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display for Ulid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
 }
 
 impl FromStr for Ulid {
@@ -41,6 +143,15 @@ impl JsonSchema for Ulid {
         SchemaObject {
             instance_type: Some(InstanceType::String.into()),
             format: Some("ulid".to_string()),
+            string: Some(Box::new(StringValidation {
+                max_length: Some(26),
+                min_length: Some(26),
+                pattern: Some(ULID_PATTERN.to_string()),
+            })),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                examples: vec![serde_json::Value::String(ULID_EXAMPLE.to_string())],
+                ..Default::default()
+            })),
             ..Default::default()
         }
             .into()